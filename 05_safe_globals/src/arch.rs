@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! Architecture-specific code.
+
+pub mod sync;
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! Architecture-specific synchronization primitives.
+
+use crate::interface;
+use core::cell::UnsafeCell;
+
+/// A pseudo-lock for teaching purposes.
+///
+/// In contrast to a real Mutex implementation, this does not protect against
+/// concurrent access from other cores in a multi-core system. It is only
+/// used to get the idea of mutual exclusion across in a single-core,
+/// non-preemptive context.
+pub struct NullLock<T> {
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T> Sync for NullLock<T> {}
+
+impl<T> NullLock<T> {
+    /// Create an instance.
+    pub const fn new(data: T) -> NullLock<T> {
+        NullLock {
+            data: UnsafeCell::new(data),
+        }
+    }
+}
+
+impl<T> interface::sync::Mutex for &NullLock<T> {
+    type Data = T;
+
+    fn lock<R>(&mut self, f: impl FnOnce(&mut Self::Data) -> R) -> R {
+        // In a real lock, there would be code encapsulating this line that
+        // ensures that this mutable reference will ever only be given out
+        // once at a time.
+        let data = unsafe { &mut *self.data.get() };
+
+        f(data)
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! Conditional exporting of Board Support Package code.
+
+mod rpi3;
+pub use rpi3::*;
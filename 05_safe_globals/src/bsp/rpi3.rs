@@ -10,22 +10,67 @@ use core::fmt;
 pub const BOOT_CORE_ID: u64 = 0;
 pub const BOOT_CORE_STACK_START: u64 = 0x80_000;
 
+/// The magic MMIO data register, shared for RX and TX.
+const QEMU_DR: *mut u8 = 0x3F21_5040 as *mut u8;
+
+/// The magic MMIO flag register. Bit 0 indicates that a received character is
+/// waiting to be read.
+const QEMU_FR: *mut u8 = 0x3F21_5048 as *mut u8;
+
 /// A mystical, magical device for generating QEMU output out of the void.
 ///
 /// The mutex protected part.
 struct QEMUOutputInner {
     chars_written: usize,
+    chars_read: usize,
 }
 
 impl QEMUOutputInner {
     const fn new() -> QEMUOutputInner {
-        QEMUOutputInner { chars_written: 0 }
+        QEMUOutputInner {
+            chars_written: 0,
+            chars_read: 0,
+        }
     }
 
     /// Send a character.
     fn write_char(&mut self, c: char) {
         unsafe {
-            core::ptr::write_volatile(0x3F21_5040 as *mut u8, c as u8);
+            core::ptr::write_volatile(QEMU_DR, c as u8);
+        }
+    }
+
+    /// Block execution until the last buffered character has been physically
+    /// put on the TX wire.
+    ///
+    /// The magic-address MMIO backend has no internal buffering, so every
+    /// write is already "on the wire" by the time it returns. A real UART
+    /// backend would busy-wait here on a "TX FIFO empty" style flag.
+    fn flush(&self) {}
+
+    /// Return `true` if a received character is waiting to be read.
+    fn rx_ready(&self) -> bool {
+        unsafe { core::ptr::read_volatile(QEMU_FR) & 0b1 == 0b1 }
+    }
+
+    /// Read a character without blocking.
+    fn read_char_nb(&mut self) -> Option<char> {
+        if !self.rx_ready() {
+            return None;
+        }
+
+        let c = unsafe { core::ptr::read_volatile(QEMU_DR) as char };
+        self.chars_read += 1;
+
+        Some(c)
+    }
+
+    /// Read a character, blocking until one is available.
+    fn read_char(&mut self) -> char {
+        loop {
+            if let Some(c) = self.read_char_nb() {
+                return c;
+            }
         }
     }
 }
@@ -77,6 +122,16 @@ impl QEMUOutput {
 /// Passthrough of `args` to the `core::fmt::Write` implementation, but guarded
 /// by a Mutex to serialize access.
 impl interface::console::Write for QEMUOutput {
+    fn write_char(&self, c: char) {
+        use interface::sync::Mutex;
+
+        let mut r = &self.inner;
+        r.lock(|i| {
+            i.write_char(c);
+            i.chars_written += 1;
+        })
+    }
+
     fn write_fmt(&self, args: core::fmt::Arguments) -> fmt::Result {
         use interface::sync::Mutex;
 
@@ -85,9 +140,30 @@ impl interface::console::Write for QEMUOutput {
         let mut r = &self.inner;
         r.lock(|i| fmt::Write::write_fmt(i, args))
     }
+
+    fn flush(&self) {
+        use interface::sync::Mutex;
+
+        let mut r = &self.inner;
+        r.lock(|i| i.flush())
+    }
 }
 
-impl interface::console::Read for QEMUOutput {}
+impl interface::console::Read for QEMUOutput {
+    fn read_char(&self) -> char {
+        use interface::sync::Mutex;
+
+        let mut r = &self.inner;
+        r.lock(|i| i.read_char())
+    }
+
+    fn read_char_nb(&self) -> Option<char> {
+        use interface::sync::Mutex;
+
+        let mut r = &self.inner;
+        r.lock(|i| i.read_char_nb())
+    }
+}
 
 impl interface::console::Statistics for QEMUOutput {
     fn chars_written(&self) -> usize {
@@ -96,19 +172,54 @@ impl interface::console::Statistics for QEMUOutput {
         let mut r = &self.inner;
         r.lock(|i| i.chars_written)
     }
+
+    fn chars_read(&self) -> usize {
+        use interface::sync::Mutex;
+
+        let mut r = &self.inner;
+        r.lock(|i| i.chars_read)
+    }
 }
 
+impl interface::console::All for QEMUOutput {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Global instances
 ////////////////////////////////////////////////////////////////////////////////
 
-static QEMU_OUTPUT: QEMUOutput = QEMUOutput::new();
+/// The console that is wired up by default at boot, before any other console
+/// has had a chance to call `console::register_console()`.
+pub(crate) static QEMU_OUTPUT: QEMUOutput = QEMUOutput::new();
 
 ////////////////////////////////////////////////////////////////////////////////
 // Implementation of the kernel's BSP calls
 ////////////////////////////////////////////////////////////////////////////////
 
-/// Return a reference to a `console::All` implementation.
-pub fn console() -> &'static impl interface::console::All {
-    &QEMU_OUTPUT
+/// ARM semihosting's `SYS_EXIT` operation, also known as
+/// `angel_SWIreason_ReportException` under its "angel" protocol name.
+const SYS_EXIT: u64 = 0x18;
+
+/// The "application exit" stop reason, paired with an `EXIT_SUCCESS` (`0`) or
+/// `EXIT_FAILURE` (`1`) exit code.
+const ADP_STOPPED_APPLICATION_EXIT: u64 = 0x0002_0026;
+
+/// Ask QEMU to shut down via an ARM semihosting `SYS_EXIT` call.
+///
+/// This never returns: either QEMU honors the request and the simulation
+/// stops, or semihosting isn't available (e.g. on real hardware) and we spin
+/// forever instead of falling through into undefined behavior.
+pub fn qemu_exit(code: u32) -> ! {
+    let exit_code: u64 = if code == 0 { 0 } else { 1 };
+    let parameter_block: [u64; 2] = [ADP_STOPPED_APPLICATION_EXIT, exit_code];
+
+    unsafe {
+        core::arch::asm!(
+            "hlt #0xf000",
+            in("x0") SYS_EXIT,
+            in("x1") &parameter_block,
+            options(nostack)
+        );
+    }
+
+    loop {}
 }
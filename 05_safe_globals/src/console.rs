@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! The console subsystem.
+//!
+//! Decouples the rest of the `kernel` from any single console
+//! implementation. Boot starts out on `bsp::QEMU_OUTPUT`, and a later
+//! initialized, real UART can take over by calling `register_console()`,
+//! without any caller of `print!`/`println!` having to change.
+
+use crate::{arch::sync::NullLock, bsp, interface};
+
+/// The currently registered console.
+static CUR_CONSOLE: NullLock<&'static dyn interface::console::All> =
+    NullLock::new(&bsp::QEMU_OUTPUT);
+
+/// Register a new console.
+pub fn register_console(new: &'static dyn interface::console::All) {
+    use interface::sync::Mutex;
+
+    let mut r = &CUR_CONSOLE;
+    r.lock(|con| *con = new);
+}
+
+/// Return a reference to the currently registered console.
+///
+/// This is the global `Console` instance.
+pub fn console() -> &'static dyn interface::console::All {
+    use interface::sync::Mutex;
+
+    let mut r = &CUR_CONSOLE;
+    r.lock(|con| *con)
+}
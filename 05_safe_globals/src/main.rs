@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! The `kernel` binary.
+
+#![feature(custom_test_frameworks)]
+#![feature(format_args_nl)]
+#![feature(panic_info_message)]
+#![no_main]
+#![no_std]
+#![reexport_test_harness_main = "test_main"]
+#![test_runner(crate::test_runner)]
+
+mod arch;
+mod bsp;
+mod console;
+mod interface;
+mod panic_wait;
+mod print;
+
+/// Entrypoint of the `kernel`.
+#[cfg(not(test))]
+fn kernel_entry() -> ! {
+    println!("Hello from Rust!");
+
+    panic!("Stopping here.")
+}
+
+/// Entrypoint used when the kernel binary is built as the `test` harness.
+#[cfg(test)]
+fn kernel_entry() -> ! {
+    test_main();
+
+    bsp::qemu_exit(0)
+}
+
+/// Run every `#[test_case]`-annotated function. Telling QEMU we're done is
+/// left to the caller, `kernel_entry()`.
+///
+/// Printing the banner first gives the harness a recognizable string to
+/// grep for, on top of the semihosting exit code.
+#[cfg(test)]
+fn test_runner(tests: &[&dyn Fn()]) {
+    println!("Running {} tests", tests.len());
+
+    for test in tests {
+        test();
+    }
+
+    println!("All tests finished successfully!");
+}
+
+/// `console().chars_written()` must advance by exactly one per character
+/// written through the `Write` trait.
+#[cfg(test)]
+#[test_case]
+fn console_chars_written_advances_by_one() {
+    use crate::interface::console::{Statistics, Write};
+
+    let before = console::console().chars_written();
+    console::console().write_char('x');
+    let after = console::console().chars_written();
+
+    assert_eq!(after, before + 1);
+}
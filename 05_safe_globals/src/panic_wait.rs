@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2018-2019 Andre Richter <andre.o.richter@gmail.com>
+
+//! A panic handler that infinitely waits, or, when built as the `test`
+//! harness, asks QEMU to exit with a failure code.
+
+use crate::{console, interface::console::Write};
+use core::panic::PanicInfo;
+
+#[cfg(test)]
+use crate::bsp;
+
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    if let Some(args) = info.message() {
+        crate::println!("\nKernel panic: {}", args);
+    } else {
+        crate::println!("\nKernel panic!");
+    }
+
+    // Flush the console before giving up, so that whatever was printed
+    // above is guaranteed to have left the device.
+    console::console().flush();
+
+    // If we are running under the `test` harness, tell QEMU we failed
+    // instead of hanging forever, so the test runner gets a deterministic
+    // exit code.
+    #[cfg(test)]
+    bsp::qemu_exit(1);
+
+    #[cfg(not(test))]
+    loop {}
+}